@@ -0,0 +1,271 @@
+use bytes::{Buf, BufMut};
+
+use crate::error::{Error, Result};
+use crate::{Uleb128Int, VALUE_MASK};
+
+fn read_uleb128_buf_body<T: Uleb128Int, B: Buf + ?Sized>(buf: &mut B) -> Result<(T, usize)> {
+    crate::int::decode(|| {
+        if !buf.has_remaining() {
+            return Err(Error::UnexpectedEof);
+        }
+
+        Ok(buf.get_u8())
+    })
+}
+
+/// Extends [`Buf`] with methods for reading numbers encoded in [unsigned
+/// LEB128] directly from the buffer cursor, advancing it in place.
+///
+/// Unlike [`ReadULeb128Ext`], this does not require [`std::io::Read`],
+/// reading straight out of the buffer's cursor instead of through an
+/// intermediate byte-at-a-time [`Read::read_exact`] call.
+///
+/// # Examples
+///
+/// Read unsigned LEB128 integers from a [`Buf`]:
+///
+/// ```
+/// use bytes::Buf;
+/// use uleb128::ReadULeb128Buf;
+///
+/// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+/// assert_eq!(128, buf.read_uleb128_u32().unwrap());
+/// assert!(!buf.has_remaining());
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`ReadULeb128Ext`]: crate::ReadULeb128Ext
+/// [`Read::read_exact`]: std::io::Read::read_exact
+pub trait ReadULeb128Buf: Buf {
+    /// Read a value of any [`Uleb128Int`] type that's encoded in [unsigned
+    /// LEB128] from the buffer cursor, advancing it in place and returning
+    /// the value and the number of bytes read.
+    ///
+    /// The concrete, per-width methods below (such as
+    /// [`read_uleb128_u32`][Self::read_uleb128_u32]) are thin wrappers
+    /// around this method.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer ends before a complete value is decoded, an
+    /// [`Error::UnexpectedEof`] is immediately returned.
+    ///
+    /// If this function encounters an encoded number with a length in bytes
+    /// greater than what is permitted, an [`Error::LengthOverflow`] is
+    /// immediately returned.
+    ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of `T`, an [`Error::ValueOverflow`] is immediately
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::ReadULeb128Buf;
+    ///
+    /// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+    /// assert_eq!((128u16, 2), buf.read_uleb128::<u16>().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    /// [`Error::UnexpectedEof`]: crate::Error::UnexpectedEof
+    fn read_uleb128<T: Uleb128Int>(&mut self) -> Result<(T, usize)> {
+        read_uleb128_buf_body(self)
+    }
+
+    /// Read an unsigned 32-bit integer that's encoded in [unsigned LEB128]
+    /// from the buffer cursor, advancing it in place.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128`][Self::read_uleb128].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::ReadULeb128Buf;
+    ///
+    /// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+    /// assert_eq!(128, buf.read_uleb128_u32().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn read_uleb128_u32(&mut self) -> Result<u32> {
+        self.read_uleb128::<u32>().map(|(value, _)| value)
+    }
+
+    /// Read an unsigned 32-bit integer that's encoded in [unsigned LEB128]
+    /// from the buffer cursor, advancing it in place and also returning the
+    /// number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128`][Self::read_uleb128].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::ReadULeb128Buf;
+    ///
+    /// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+    /// assert_eq!((128, 2), buf.read_uleb128_u32_len().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn read_uleb128_u32_len(&mut self) -> Result<(u32, usize)> {
+        self.read_uleb128::<u32>()
+    }
+
+    /// Read an unsigned 64-bit integer that's encoded in [unsigned LEB128]
+    /// from the buffer cursor, advancing it in place.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128`][Self::read_uleb128].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::ReadULeb128Buf;
+    ///
+    /// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+    /// assert_eq!(128, buf.read_uleb128_u64().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn read_uleb128_u64(&mut self) -> Result<u64> {
+        self.read_uleb128::<u64>().map(|(value, _)| value)
+    }
+
+    /// Read an unsigned 64-bit integer that's encoded in [unsigned LEB128]
+    /// from the buffer cursor, advancing it in place and also returning the
+    /// number of bytes read.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128`][Self::read_uleb128].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::ReadULeb128Buf;
+    ///
+    /// let mut buf = &[0b1000_0000, 0b0000_0001][..];
+    /// assert_eq!((128, 2), buf.read_uleb128_u64_len().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn read_uleb128_u64_len(&mut self) -> Result<(u64, usize)> {
+        self.read_uleb128::<u64>()
+    }
+}
+
+impl<B: Buf + ?Sized> ReadULeb128Buf for B {}
+
+fn write_uleb128_buf_body<T: Uleb128Int, B: BufMut + ?Sized>(buf: &mut B, value: T) -> usize {
+    let mut value = value;
+    let mut bytes_written = 0;
+
+    loop {
+        let mut byte = value.to_low_byte();
+        value = value.shr7();
+
+        if !value.is_zero() {
+            byte |= !VALUE_MASK;
+        }
+
+        buf.put_u8(byte);
+        bytes_written += 1;
+
+        if value.is_zero() {
+            return bytes_written;
+        }
+    }
+}
+
+/// Extends [`BufMut`] with methods for writing unsigned integers directly to
+/// the buffer cursor encoded in [unsigned LEB128], advancing it in place.
+///
+/// Unlike [`WriteULeb128Ext`], this does not require [`std::io::Write`],
+/// writing straight into the buffer's spare capacity through [`BufMut::put_u8`]
+/// rather than through an intermediate [`Write::write_all`] call.
+///
+/// # Examples
+///
+/// Write unsigned integers to a [`BufMut`] encoded in LEB128:
+///
+/// ```
+/// use uleb128::WriteULeb128Buf;
+///
+/// let mut buf = vec![];
+/// buf.write_uleb128_u32(128);
+///
+/// assert_eq!(buf, vec![0b1000_0000, 0b0000_0001]);
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`WriteULeb128Ext`]: crate::WriteULeb128Ext
+/// [`Write::write_all`]: std::io::Write::write_all
+pub trait WriteULeb128Buf: BufMut {
+    /// Write a value of any [`Uleb128Int`] type to the buffer cursor encoded
+    /// in [unsigned LEB128], advancing it in place and returning the number
+    /// of bytes written.
+    ///
+    /// The concrete, per-width methods below (such as
+    /// [`write_uleb128_u32`][Self::write_uleb128_u32]) are thin wrappers
+    /// around this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::WriteULeb128Buf;
+    ///
+    /// let mut buf = vec![];
+    /// assert_eq!(2, buf.write_uleb128(128u16));
+    ///
+    /// assert_eq!(buf, vec![0b1000_0000, 0b0000_0001]);
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn write_uleb128<T: Uleb128Int>(&mut self, value: T) -> usize {
+        write_uleb128_buf_body(self, value)
+    }
+
+    /// Write an unsigned 32-bit integer to the buffer cursor encoded in
+    /// [unsigned LEB128], advancing it in place and returning the number of
+    /// bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::WriteULeb128Buf;
+    ///
+    /// let mut buf = vec![];
+    /// assert_eq!(5, buf.write_uleb128_u32(2_147_483_647));
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn write_uleb128_u32(&mut self, value: u32) -> usize {
+        self.write_uleb128(value)
+    }
+
+    /// Write an unsigned 64-bit integer to the buffer cursor encoded in
+    /// [unsigned LEB128], advancing it in place and returning the number of
+    /// bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::WriteULeb128Buf;
+    ///
+    /// let mut buf = vec![];
+    /// assert_eq!(9, buf.write_uleb128_u64(9_223_372_036_854_775_807));
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    fn write_uleb128_u64(&mut self, value: u64) -> usize {
+        self.write_uleb128(value)
+    }
+}
+
+impl<B: BufMut + ?Sized> WriteULeb128Buf for B {}
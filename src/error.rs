@@ -1,5 +1,8 @@
+#[cfg(feature = "std")]
 use std::io;
 
+use core::fmt;
+
 /// A specialized [`Result`] type for unsigned LEB128 operations.
 ///
 /// This type is broadly used across [`uleb128`](crate) for any operation which
@@ -22,25 +25,64 @@ use std::io;
 /// [`Ok`]: std::result::Result::Ok
 /// [`()`]: https://doc.rust-lang.org/std/primitive.unit.html
 /// [prelude]: https://doc.rust-lang.org/std/prelude/index.html
-pub type Result<T = (), E = Error> = std::result::Result<T, E>;
+pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// The error type for LEB128 operations of the [`ReadULeb128Ext`] and the
+/// [`WriteULeb128Ext`] extension traits.
+///
+/// This is hand-written rather than built with `quick_error!`, since
+/// `quick_error!` unconditionally emits `impl std::error::Error`/`std::fmt`
+/// machinery regardless of per-variant `#[cfg]`s, which breaks the `no_std`
+/// build this type is meant to support with the `std` feature disabled.
+///
+/// [`ReadULeb128Ext`]: crate::ReadULeb128Ext
+/// [`WriteULeb128Ext`]: crate::WriteULeb128Ext
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O operation failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// The read operation encountered data that was too long.
+    LengthOverflow(usize),
+    /// The read operation encountered a final byte whose significant
+    /// bits exceed the target type's range.
+    ValueOverflow,
+    /// The destination buffer was too small to hold the encoded value.
+    BufferTooSmall,
+    /// The source buffer ended before a complete value could be decoded.
+    UnexpectedEof,
+}
 
-quick_error! {
-    /// The error type for LEB128 operations of the [`ReadULeb128Ext`] and the
-    /// [`WriteULeb128Ext`] extension traits.
-    ///
-    /// [`ReadULeb128Ext`]: crate::ReadULeb128Ext
-    /// [`WriteULeb128Ext`]: crate::WriteULeb128Ext
-    #[derive(Debug)]
-    pub enum Error {
-        /// An I/O operation failed.
-        Io(err: io::Error) {
-            from()
-            source(err)
-            display("io error: {}", err)
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Error::Io(err) => write!(f, "io error: {}", err),
+            Error::LengthOverflow(max) => write!(f, "can not read more than {} bytes", max),
+            Error::ValueOverflow => write!(f, "encoded value overflows the target type"),
+            Error::BufferTooSmall => {
+                write!(f, "destination buffer is too small to hold the encoded value")
+            }
+            Error::UnexpectedEof => {
+                write!(f, "buffer ended before a complete value could be decoded")
+            }
         }
-        /// The read operation encountered data that was too long.
-        LengthOverflow(max: usize) {
-            display("can not read more than {} bytes", max)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::{Error, Result};
+use crate::{
+    ULEB128_U128_MAX_LENGTH, ULEB128_U16_MAX_LENGTH, ULEB128_U32_MAX_LENGTH,
+    ULEB128_U64_MAX_LENGTH, ULEB128_U8_MAX_LENGTH, ULEB128_USIZE_MAX_LENGTH, VALUE_LENGTH,
+    VALUE_MASK,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The unsigned integer types that can be read and written as [unsigned
+/// LEB128] through [`ReadULeb128Ext::read_uleb128`] and
+/// [`WriteULeb128Ext::write_uleb128`].
+///
+/// This trait is sealed and implemented only for `u8`, `u16`, `u32`, `u64`,
+/// `u128`, and [`usize`]; it cannot be implemented outside of [`uleb128`](crate).
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`ReadULeb128Ext::read_uleb128`]: crate::ReadULeb128Ext::read_uleb128
+/// [`WriteULeb128Ext::write_uleb128`]: crate::WriteULeb128Ext::write_uleb128
+pub trait Uleb128Int: private::Sealed + Copy {
+    /// The maximum number of bytes needed to encode this type as [unsigned
+    /// LEB128].
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    const MAX_LENGTH: usize;
+
+    #[doc(hidden)]
+    const BITS: usize;
+
+    #[doc(hidden)]
+    fn zero() -> Self;
+
+    #[doc(hidden)]
+    fn is_zero(self) -> bool;
+
+    #[doc(hidden)]
+    fn from_low_byte(byte: u8) -> Self;
+
+    #[doc(hidden)]
+    fn to_low_byte(self) -> u8;
+
+    #[doc(hidden)]
+    fn shl(self, amount: usize) -> Self;
+
+    #[doc(hidden)]
+    fn shr7(self) -> Self;
+
+    #[doc(hidden)]
+    fn or_assign(&mut self, other: Self);
+}
+
+macro_rules! impl_uleb128_int {
+    ($ty:ty, $max_length:expr) => {
+        impl private::Sealed for $ty {}
+
+        impl Uleb128Int for $ty {
+            const MAX_LENGTH: usize = $max_length;
+            const BITS: usize = <$ty>::BITS as usize;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+
+            fn from_low_byte(byte: u8) -> Self {
+                (byte & VALUE_MASK) as $ty
+            }
+
+            fn to_low_byte(self) -> u8 {
+                (self & VALUE_MASK as $ty) as u8
+            }
+
+            fn shl(self, amount: usize) -> Self {
+                self << amount
+            }
+
+            fn shr7(self) -> Self {
+                self >> crate::VALUE_LENGTH
+            }
+
+            fn or_assign(&mut self, other: Self) {
+                *self |= other;
+            }
+        }
+    };
+}
+
+impl_uleb128_int!(u8, ULEB128_U8_MAX_LENGTH);
+impl_uleb128_int!(u16, ULEB128_U16_MAX_LENGTH);
+impl_uleb128_int!(u32, ULEB128_U32_MAX_LENGTH);
+impl_uleb128_int!(u64, ULEB128_U64_MAX_LENGTH);
+impl_uleb128_int!(u128, ULEB128_U128_MAX_LENGTH);
+impl_uleb128_int!(usize, ULEB128_USIZE_MAX_LENGTH);
+
+/// Decode a value of any [`Uleb128Int`] type from a byte source, sharing the
+/// accumulate-and-overflow-check loop across [`ReadULeb128Ext`], the
+/// `no_std` slice codec, and the `bytes` integration, which otherwise differ
+/// only in how they fetch the next byte.
+///
+/// `next_byte` is called once per encoded byte; it's responsible for
+/// signaling its own end-of-input condition (e.g. [`Error::UnexpectedEof`]
+/// or an [`Error::Io`]).
+///
+/// [`ReadULeb128Ext`]: crate::ReadULeb128Ext
+/// [`Error::Io`]: crate::Error::Io
+pub(crate) fn decode<T, F>(mut next_byte: F) -> Result<(T, usize)>
+where
+    T: Uleb128Int,
+    F: FnMut() -> Result<u8>,
+{
+    let mut value = T::zero();
+    let mut bytes_read = 0;
+
+    loop {
+        let byte = next_byte()?;
+        let byte_value = T::from_low_byte(byte);
+
+        bytes_read += 1;
+        if bytes_read > T::MAX_LENGTH {
+            return Err(Error::LengthOverflow(T::MAX_LENGTH));
+        }
+
+        if bytes_read == T::MAX_LENGTH {
+            let max_bits = T::BITS - VALUE_LENGTH * (T::MAX_LENGTH - 1);
+            let max_byte_value = (1u8 << max_bits) - 1;
+            if byte & VALUE_MASK > max_byte_value {
+                return Err(Error::ValueOverflow);
+            }
+        }
+
+        value.or_assign(byte_value.shl(VALUE_LENGTH * (bytes_read - 1)));
+
+        if (byte & !VALUE_MASK) == 0 {
+            break;
+        }
+    }
+
+    Ok((value, bytes_read))
+}
@@ -1,36 +1,14 @@
 use std::io::{Read, Write};
 
 use crate::error::{Error, Result};
-use crate::{ULEB128_U32_MAX_LENGTH, ULEB128_U64_MAX_LENGTH};
+use crate::{ULEB128_U32_MAX_LENGTH, ULEB128_U64_MAX_LENGTH, Uleb128Int, VALUE_LENGTH, VALUE_MASK};
 
-const VALUE_MASK: u8 = 0b0111_1111;
-const VALUE_LENGTH: usize = 7;
-
-macro_rules! read_method_body {
-    ($self:expr, $ty:ty, $len:expr) => {{
-        let mut value = 0;
-        let mut bytes_read = 0;
-
-        loop {
-            let mut buf = [0; 1];
-            $self.read_exact(&mut buf)?;
-
-            let byte = buf[0];
-            let byte_value = (byte & VALUE_MASK) as $ty;
-            value |= byte_value << (VALUE_LENGTH * bytes_read);
-
-            bytes_read += 1;
-            if bytes_read > $len {
-                return Err(Error::LengthOverflow($len));
-            }
-
-            if (byte & !VALUE_MASK) == 0 {
-                break;
-            }
-        }
-
-        Ok(value)
-    }};
+fn read_uleb128_body<T: Uleb128Int, R: Read + ?Sized>(reader: &mut R) -> Result<(T, usize)> {
+    crate::int::decode(|| {
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf)?;
+        Ok(buf[0])
+    })
 }
 
 /// Extends [`Read`][reader] with methods for reading numbers encoded in
@@ -58,6 +36,45 @@ macro_rules! read_method_body {
 /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
 /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
 pub trait ReadULeb128Ext: Read {
+    /// Read a value of any [`Uleb128Int`] type that's encoded in [unsigned
+    /// LEB128] from the underlying [reader], returning the value and the
+    /// number of bytes read.
+    ///
+    /// The concrete, per-width methods below (such as
+    /// [`read_uleb128_u32`][Self::read_uleb128_u32]) are thin wrappers
+    /// around this method.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Read::read_exact`].
+    ///
+    /// If this function encounters an encoded number with a length in bytes
+    /// greater than what is permitted, an [`Error::LengthOverflow`] is
+    /// immediately returned.
+    ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of `T`, an [`Error::ValueOverflow`] is immediately
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadULeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b1000_0000, 0b0000_0001]);
+    /// assert_eq!((128u16, 2), rdr.read_uleb128::<u16>().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    /// [`io::Error`]: std::io::Error
+    fn read_uleb128<T: Uleb128Int>(&mut self) -> Result<(T, usize)> {
+        read_uleb128_body(self)
+    }
+
     /// Read an unsigned 32-bit integer that's encoded in [unsigned LEB128]
     /// from the underlying [reader].
     ///
@@ -71,6 +88,10 @@ pub trait ReadULeb128Ext: Read {
     /// greater than what is permitted, an [`Error::LengthOverflow`] is
     /// immediately returned.
     ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of a 32-bit integer, an [`Error::ValueOverflow`] is
+    /// immediately returned.
+    ///
     /// # Examples
     ///
     /// Read an unsigned LEB128-encoded, 32-bit integer:
@@ -87,11 +108,54 @@ pub trait ReadULeb128Ext: Read {
     /// assert_eq!(2_147_483_647, rdr.read_uleb128_u32().unwrap());
     /// ```
     ///
+    /// Reading a final byte whose significant bits overflow a 32-bit
+    /// integer:
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadULeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     0b1111_1111, 0b1111_1111, 0b1111_1111, 0b1111_1111, 0b0001_0000
+    /// ]);
+    ///
+    /// assert!(rdr.read_uleb128_u32().is_err());
+    /// ```
+    ///
     /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
     /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
     /// [`io::Error`]: std::io::Error
     fn read_uleb128_u32(&mut self) -> Result<u32> {
-        read_method_body!(self, u32, ULEB128_U32_MAX_LENGTH)
+        self.read_uleb128::<u32>().map(|(value, _)| value)
+    }
+
+    /// Read an unsigned 32-bit integer that's encoded in [unsigned LEB128]
+    /// from the underlying [reader], also returning the number of bytes
+    /// read.
+    ///
+    /// This is identical to [`read_uleb128_u32`][Self::read_uleb128_u32],
+    /// except that it also reports how many bytes were consumed from the
+    /// underlying [reader], which is useful for callers that need to track
+    /// their position in the stream.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128_u32`][Self::read_uleb128_u32].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadULeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b1000_0000, 0b0000_0001]);
+    /// assert_eq!((128, 2), rdr.read_uleb128_u32_len().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn read_uleb128_u32_len(&mut self) -> Result<(u32, usize)> {
+        self.read_uleb128::<u32>()
     }
 
     /// Read an unsigned 64-bit integer that's encoded in [unsigned LEB128]
@@ -107,6 +171,10 @@ pub trait ReadULeb128Ext: Read {
     /// greater than what is permitted, an [`Error::LengthOverflow`] is
     /// immediately returned.
     ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of a 64-bit integer, an [`Error::ValueOverflow`] is
+    /// immediately returned.
+    ///
     /// # Examples
     ///
     /// Read an unsigned LEB128-encoded, 64-bit integer:
@@ -128,30 +196,63 @@ pub trait ReadULeb128Ext: Read {
     /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
     /// [`io::Error`]: std::io::Error
     fn read_uleb128_u64(&mut self) -> Result<u64> {
-        read_method_body!(self, u64, ULEB128_U64_MAX_LENGTH)
+        self.read_uleb128::<u64>().map(|(value, _)| value)
+    }
+
+    /// Read an unsigned 64-bit integer that's encoded in [unsigned LEB128]
+    /// from the underlying [reader], also returning the number of bytes
+    /// read.
+    ///
+    /// This is identical to [`read_uleb128_u64`][Self::read_uleb128_u64],
+    /// except that it also reports how many bytes were consumed from the
+    /// underlying [reader], which is useful for callers that need to track
+    /// their position in the stream.
+    ///
+    /// # Errors
+    ///
+    /// See [`read_uleb128_u64`][Self::read_uleb128_u64].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadULeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b1000_0000, 0b0000_0001]);
+    /// assert_eq!((128, 2), rdr.read_uleb128_u64_len().unwrap());
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn read_uleb128_u64_len(&mut self) -> Result<(u64, usize)> {
+        self.read_uleb128::<u64>()
     }
 }
 
 impl<R: Read + ?Sized> ReadULeb128Ext for R {}
 
-macro_rules! write_method_body {
-    ($self:expr, $value:ident, $ty:ty) => {{
-        let mut value = $value;
-        loop {
-            let mut byte = value & VALUE_MASK as $ty;
-            value >>= VALUE_LENGTH;
+fn write_uleb128_body<T: Uleb128Int, W: Write + ?Sized>(
+    writer: &mut W,
+    value: T,
+) -> Result<usize> {
+    let mut value = value;
+    let mut bytes_written = 0;
 
-            if value != 0 {
-                byte |= !VALUE_MASK as $ty;
-            }
+    loop {
+        let mut byte = value.to_low_byte();
+        value = value.shr7();
 
-            $self.write_all(&[byte as u8])?;
+        if !value.is_zero() {
+            byte |= !VALUE_MASK;
+        }
 
-            if value == 0 {
-                return Ok(());
-            }
+        writer.write_all(&[byte])?;
+        bytes_written += 1;
+
+        if value.is_zero() {
+            return Ok(bytes_written);
         }
-    }};
+    }
 }
 
 /// Extends [`Write`][writer] with methods for writing unsigned integers to the
@@ -165,9 +266,9 @@ macro_rules! write_method_body {
 /// use uleb128::WriteULeb128Ext;
 ///
 /// let mut wtr = vec![];
-/// wtr.write_uleb128_u32(127).unwrap();
-/// wtr.write_uleb128_u32(128).unwrap();
-/// wtr.write_uleb128_u32(129).unwrap();
+/// assert_eq!(1, wtr.write_uleb128_u32(127).unwrap());
+/// assert_eq!(2, wtr.write_uleb128_u32(128).unwrap());
+/// assert_eq!(2, wtr.write_uleb128_u32(129).unwrap());
 ///
 /// assert_eq!(wtr, vec![
 ///     0b0111_1111, // 127
@@ -179,8 +280,39 @@ macro_rules! write_method_body {
 /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
 /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
 pub trait WriteULeb128Ext: Write {
-    /// Write an unsigned 32-bit integer to the underlying [writer] encoded in
-    /// [unsigned LEB128].
+    /// Write a value of any [`Uleb128Int`] type to the underlying [writer]
+    /// encoded in [unsigned LEB128], returning the number of bytes written.
+    ///
+    /// The concrete, per-width methods below (such as
+    /// [`write_uleb128_u32`][Self::write_uleb128_u32]) are thin wrappers
+    /// around this method.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uleb128::WriteULeb128Ext;
+    ///
+    /// let mut wtr = vec![];
+    /// assert_eq!(2, wtr.write_uleb128(128u16).unwrap());
+    ///
+    /// assert_eq!(wtr, vec![0b1000_0000, 0b0000_0001]);
+    /// ```
+    ///
+    /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+    /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`io::Error`]: std::io::Error
+    fn write_uleb128<T: Uleb128Int>(&mut self, value: T) -> Result<usize> {
+        write_uleb128_body(self, value)
+    }
+
+    /// Write an unsigned 32-bit integer to the underlying [writer] encoded
+    /// in [unsigned LEB128], returning the number of bytes written.
     ///
     /// # Errors
     ///
@@ -196,7 +328,7 @@ pub trait WriteULeb128Ext: Write {
     /// use uleb128::WriteULeb128Ext;
     ///
     /// let mut wtr = vec![];
-    /// wtr.write_uleb128_u32(2_147_483_647).unwrap();
+    /// assert_eq!(5, wtr.write_uleb128_u32(2_147_483_647).unwrap());
     ///
     /// assert_eq!(wtr, vec![
     ///     // 2_147_483_647
@@ -207,12 +339,12 @@ pub trait WriteULeb128Ext: Write {
     /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
     /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
     /// [`io::Error`]: std::io::Error
-    fn write_uleb128_u32(&mut self, value: u32) -> Result {
-        write_method_body!(self, value, u32)
+    fn write_uleb128_u32(&mut self, value: u32) -> Result<usize> {
+        self.write_uleb128(value)
     }
 
-    /// Write an unsigned 64-bit integer to the underlying [writer] encoded in
-    /// [unsigned LEB128].
+    /// Write an unsigned 64-bit integer to the underlying [writer] encoded
+    /// in [unsigned LEB128], returning the number of bytes written.
     ///
     /// # Errors
     ///
@@ -228,7 +360,7 @@ pub trait WriteULeb128Ext: Write {
     /// use uleb128::WriteULeb128Ext;
     ///
     /// let mut wtr = vec![];
-    /// wtr.write_uleb128_u64(9_223_372_036_854_775_807).unwrap();
+    /// assert_eq!(9, wtr.write_uleb128_u64(9_223_372_036_854_775_807).unwrap());
     ///
     /// assert_eq!(wtr, vec![
     ///     // 9_223_372_036_854_775_807
@@ -240,9 +372,354 @@ pub trait WriteULeb128Ext: Write {
     /// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
     /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
     /// [`io::Error`]: std::io::Error
-    fn write_uleb128_u64(&mut self, value: u64) -> Result {
-        write_method_body!(self, value, u64)
+    fn write_uleb128_u64(&mut self, value: u64) -> Result<usize> {
+        self.write_uleb128(value)
     }
 }
 
 impl<W: Write + ?Sized> WriteULeb128Ext for W {}
+
+macro_rules! read_sleb128_body {
+    ($self:expr, $ty:ty, $len:expr) => {{
+        let mut value: $ty = 0;
+        let mut bytes_read = 0;
+        let mut byte;
+
+        loop {
+            let mut buf = [0; 1];
+            $self.read_exact(&mut buf)?;
+
+            byte = buf[0];
+
+            bytes_read += 1;
+            if bytes_read > $len {
+                return Err(Error::LengthOverflow($len));
+            }
+
+            if bytes_read == $len {
+                // Bits above `max_bits` in this final byte aren't overflow —
+                // they're sign-extension padding, and must replicate the
+                // sign bit (0 for positive values, all-ones for negative
+                // ones) rather than fit within an unsigned bound.
+                let max_bits = <$ty>::BITS as usize - VALUE_LENGTH * ($len - 1);
+                let padding_mask = VALUE_MASK & !((1u8 << max_bits) - 1);
+                let padding = byte & padding_mask;
+                let sign_bit = 1u8 << (max_bits - 1);
+                let overflow = if byte & sign_bit != 0 {
+                    padding != padding_mask
+                } else {
+                    padding != 0
+                };
+                if overflow {
+                    return Err(Error::ValueOverflow);
+                }
+            }
+
+            let byte_value = (byte & VALUE_MASK) as $ty;
+            value |= byte_value << (VALUE_LENGTH * (bytes_read - 1));
+
+            if (byte & !VALUE_MASK) == 0 {
+                break;
+            }
+        }
+
+        let shift = VALUE_LENGTH * bytes_read;
+        if shift < <$ty>::BITS as usize && (byte & 0x40) != 0 {
+            value |= !0 << shift;
+        }
+
+        Ok((value, bytes_read))
+    }};
+}
+
+/// Extends [`Read`][reader] with methods for reading numbers encoded in
+/// [signed LEB128].
+///
+/// # Examples
+///
+/// Read signed LEB128 integers from a [reader]:
+///
+/// ```
+/// use std::io::Cursor;
+/// use uleb128::ReadSLeb128Ext;
+///
+/// let mut rdr = Cursor::new(vec![
+///     0b0111_1110, // -2
+///     0b1111_1111, 0b0000_0000, // 127
+/// ]);
+///
+/// assert_eq!(-2, rdr.read_sleb128_i32().unwrap());
+/// assert_eq!(127, rdr.read_sleb128_i32().unwrap());
+/// ```
+///
+/// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+/// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+pub trait ReadSLeb128Ext: Read {
+    /// Read a signed 32-bit integer that's encoded in [signed LEB128] from
+    /// the underlying [reader].
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Read::read_exact`].
+    ///
+    /// If this function encounters an encoded number with a length in bytes
+    /// greater than what is permitted, an [`Error::LengthOverflow`] is
+    /// immediately returned.
+    ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of a 32-bit integer, an [`Error::ValueOverflow`] is
+    /// immediately returned.
+    ///
+    /// # Examples
+    ///
+    /// Read a signed LEB128-encoded, 32-bit integer:
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadSLeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b0111_1110]);
+    ///
+    /// assert_eq!(-2, rdr.read_sleb128_i32().unwrap());
+    /// ```
+    ///
+    /// The full 5-byte encoding of a large negative value, whose final byte
+    /// is padded with sign-extension bits rather than overflowing:
+    ///
+    /// ```
+    /// use uleb128::{WriteSLeb128Ext, ReadSLeb128Ext};
+    ///
+    /// let mut wtr = vec![];
+    /// wtr.write_sleb128_i32(i32::MIN).unwrap();
+    ///
+    /// let mut rdr = std::io::Cursor::new(wtr);
+    /// assert_eq!(i32::MIN, rdr.read_sleb128_i32().unwrap());
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    /// [`io::Error`]: std::io::Error
+    fn read_sleb128_i32(&mut self) -> Result<i32> {
+        self.read_sleb128_i32_len().map(|(value, _)| value)
+    }
+
+    /// Read a signed 32-bit integer that's encoded in [signed LEB128] from
+    /// the underlying [reader], also returning the number of bytes read.
+    ///
+    /// This is identical to [`read_sleb128_i32`][Self::read_sleb128_i32],
+    /// except that it also reports how many bytes were consumed from the
+    /// underlying [reader], which is useful for callers that need to track
+    /// their position in the stream (such as when interleaving signed and
+    /// unsigned LEB128 values, as in DWARF and WebAssembly).
+    ///
+    /// # Errors
+    ///
+    /// See [`read_sleb128_i32`][Self::read_sleb128_i32].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadSLeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b0111_1110]);
+    /// assert_eq!((-2, 1), rdr.read_sleb128_i32_len().unwrap());
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn read_sleb128_i32_len(&mut self) -> Result<(i32, usize)> {
+        read_sleb128_body!(self, i32, ULEB128_U32_MAX_LENGTH)
+    }
+
+    /// Read a signed 64-bit integer that's encoded in [signed LEB128] from
+    /// the underlying [reader].
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Read::read_exact`].
+    ///
+    /// If this function encounters an encoded number with a length in bytes
+    /// greater than what is permitted, an [`Error::LengthOverflow`] is
+    /// immediately returned.
+    ///
+    /// If this function encounters a final byte whose significant bits
+    /// exceed the range of a 64-bit integer, an [`Error::ValueOverflow`] is
+    /// immediately returned.
+    ///
+    /// # Examples
+    ///
+    /// Read a signed LEB128-encoded, 64-bit integer:
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadSLeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b0111_1110]);
+    ///
+    /// assert_eq!(-2, rdr.read_sleb128_i64().unwrap());
+    /// ```
+    ///
+    /// The full 10-byte encoding of a large negative value, whose final byte
+    /// is padded with sign-extension bits rather than overflowing:
+    ///
+    /// ```
+    /// use uleb128::{WriteSLeb128Ext, ReadSLeb128Ext};
+    ///
+    /// let mut wtr = vec![];
+    /// wtr.write_sleb128_i64(i64::MIN).unwrap();
+    ///
+    /// let mut rdr = std::io::Cursor::new(wtr);
+    /// assert_eq!(i64::MIN, rdr.read_sleb128_i64().unwrap());
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    /// [`io::Error`]: std::io::Error
+    fn read_sleb128_i64(&mut self) -> Result<i64> {
+        self.read_sleb128_i64_len().map(|(value, _)| value)
+    }
+
+    /// Read a signed 64-bit integer that's encoded in [signed LEB128] from
+    /// the underlying [reader], also returning the number of bytes read.
+    ///
+    /// This is identical to [`read_sleb128_i64`][Self::read_sleb128_i64],
+    /// except that it also reports how many bytes were consumed from the
+    /// underlying [reader], which is useful for callers that need to track
+    /// their position in the stream (such as when interleaving signed and
+    /// unsigned LEB128 values, as in DWARF and WebAssembly).
+    ///
+    /// # Errors
+    ///
+    /// See [`read_sleb128_i64`][Self::read_sleb128_i64].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use uleb128::ReadSLeb128Ext;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b0111_1110]);
+    /// assert_eq!((-2, 1), rdr.read_sleb128_i64_len().unwrap());
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [reader]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn read_sleb128_i64_len(&mut self) -> Result<(i64, usize)> {
+        read_sleb128_body!(self, i64, ULEB128_U64_MAX_LENGTH)
+    }
+}
+
+impl<R: Read + ?Sized> ReadSLeb128Ext for R {}
+
+macro_rules! write_sleb128_body {
+    ($self:expr, $value:ident, $ty:ty) => {{
+        let mut value = $value;
+        let mut bytes_written = 0;
+
+        loop {
+            let mut byte = (value & VALUE_MASK as $ty) as u8;
+            value >>= VALUE_LENGTH;
+
+            let done = (value == 0 && (byte & 0x40) == 0) || (value == -1 && (byte & 0x40) != 0);
+            if !done {
+                byte |= !VALUE_MASK;
+            }
+
+            $self.write_all(&[byte])?;
+            bytes_written += 1;
+
+            if done {
+                return Ok(bytes_written);
+            }
+        }
+    }};
+}
+
+/// Extends [`Write`][writer] with methods for writing signed integers to the
+/// underlying writer encoded in [signed LEB128].
+///
+/// # Examples
+///
+/// Write signed integers to a [writer] encoded in LEB128:
+///
+/// ```
+/// use uleb128::WriteSLeb128Ext;
+///
+/// let mut wtr = vec![];
+/// wtr.write_sleb128_i32(-2).unwrap();
+/// wtr.write_sleb128_i32(127).unwrap();
+///
+/// assert_eq!(wtr, vec![
+///     0b0111_1110, // -2
+///     0b1111_1111, 0b0000_0000, // 127
+/// ]);
+/// ```
+///
+/// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+/// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
+pub trait WriteSLeb128Ext: Write {
+    /// Write a signed 32-bit integer to the underlying [writer] encoded in
+    /// [signed LEB128], returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// Write a signed 32-bit integer to a [writer] encoded in LEB128:
+    ///
+    /// ```
+    /// use uleb128::WriteSLeb128Ext;
+    ///
+    /// let mut wtr = vec![];
+    /// assert_eq!(1, wtr.write_sleb128_i32(-2).unwrap());
+    ///
+    /// assert_eq!(wtr, vec![0b0111_1110]);
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`io::Error`]: std::io::Error
+    fn write_sleb128_i32(&mut self, value: i32) -> Result<usize> {
+        write_sleb128_body!(self, value, i32)
+    }
+
+    /// Write a signed 64-bit integer to the underlying [writer] encoded in
+    /// [signed LEB128], returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error when performing an I/O operation,
+    /// then this function immediately returns an [`Error::Io`] to propagate the
+    /// [`io::Error`] returned by an internal call to [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// Write a signed 64-bit integer to a [writer] encoded in LEB128:
+    ///
+    /// ```
+    /// use uleb128::WriteSLeb128Ext;
+    ///
+    /// let mut wtr = vec![];
+    /// assert_eq!(1, wtr.write_sleb128_i64(-2).unwrap());
+    ///
+    /// assert_eq!(wtr, vec![0b0111_1110]);
+    /// ```
+    ///
+    /// [signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
+    /// [writer]: https://doc.rust-lang.org/std/io/trait.Write.html
+    /// [`io::Error`]: std::io::Error
+    fn write_sleb128_i64(&mut self, value: i64) -> Result<usize> {
+        write_sleb128_body!(self, value, i64)
+    }
+}
+
+impl<W: Write + ?Sized> WriteSLeb128Ext for W {}
@@ -32,33 +32,135 @@
 //! assert_eq!(wtr, vec![0b1000_0000, 0b0000_0001]);
 //! ```
 //!
+//! # Signed
+//!
+//! [Signed LEB128] values are supported through the [`ReadSLeb128Ext`] and
+//! [`WriteSLeb128Ext`] extension traits, which mirror their unsigned
+//! counterparts above.
+//!
+//! ```
+//! use uleb128::{ReadSLeb128Ext, WriteSLeb128Ext};
+//!
+//! let mut wtr = vec![];
+//! wtr.write_sleb128_i32(-129).unwrap();
+//!
+//! let mut rdr = std::io::Cursor::new(wtr);
+//! assert_eq!(-129, rdr.read_sleb128_i32().unwrap());
+//! ```
+//!
+//! # Other widths
+//!
+//! [`ReadULeb128Ext`] and [`WriteULeb128Ext`] also offer generic
+//! [`read_uleb128`][ReadULeb128Ext::read_uleb128] and
+//! [`write_uleb128`][WriteULeb128Ext::write_uleb128] methods for every
+//! [`Uleb128Int`] type — `u8`, `u16`, `u32`, `u64`, `u128`, and [`usize`] —
+//! of which the `u32`/`u64` methods above are thin wrappers.
+//!
+//! ```
+//! use uleb128::{ReadULeb128Ext, WriteULeb128Ext};
+//!
+//! let mut wtr = vec![];
+//! wtr.write_uleb128(300u16).unwrap();
+//!
+//! let mut rdr = std::io::Cursor::new(wtr);
+//! assert_eq!(300u16, rdr.read_uleb128::<u16>().unwrap().0);
+//! ```
+//!
+//! # `no_std`
+//!
+//! With the default `std` feature disabled, the [`Read`]/[`Write`]-based
+//! traits above are unavailable, but the crate still builds under `no_std`
+//! and exposes a slice-based codec through [`uleb128_u32_encode`],
+//! [`uleb128_u32_decode`], and their 64-bit counterparts, which read from and
+//! write to `&[u8]`/`&mut [u8]` directly.
+//!
+//! ```
+//! use uleb128::{uleb128_u32_decode, uleb128_u32_encode};
+//!
+//! let mut buf = [0; 2];
+//! let written = uleb128_u32_encode(128, &mut buf).unwrap();
+//!
+//! let (value, read) = uleb128_u32_decode(&buf[..written]).unwrap();
+//! assert_eq!(128, value);
+//! assert_eq!(written, read);
+//! ```
+//!
+//! # `bytes`
+//!
+//! With the optional `bytes` feature enabled, the [`ReadULeb128Buf`] and
+//! [`WriteULeb128Buf`] extension traits read and write values directly
+//! against a [`Buf`]/[`BufMut`] cursor, advancing it in place without going
+//! through [`Read`]/[`Write`].
+//!
+//! ```
+//! use uleb128::{ReadULeb128Buf, WriteULeb128Buf};
+//!
+//! let mut buf = vec![];
+//! buf.write_uleb128_u32(128);
+//!
+//! let mut cursor = &buf[..];
+//! assert_eq!(128, cursor.read_uleb128_u32().unwrap());
+//! ```
+//!
 //! [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+//! [Signed LEB128]: https://en.wikipedia.org/wiki/LEB128#Signed_LEB128
 //! [`ReadULeb128Ext`]: crate::ReadULeb128Ext
+//! [`ReadSLeb128Ext`]: crate::ReadSLeb128Ext
+//! [`WriteSLeb128Ext`]: crate::WriteSLeb128Ext
 //! [`Read`]: std::io::Read
 //! [`Write`]: std::io::Write
+//! [`uleb128_u32_encode`]: crate::uleb128_u32_encode
+//! [`uleb128_u32_decode`]: crate::uleb128_u32_decode
+//! [`Uleb128Int`]: crate::Uleb128Int
+//! [ReadULeb128Ext::read_uleb128]: crate::ReadULeb128Ext::read_uleb128
+//! [WriteULeb128Ext::write_uleb128]: crate::WriteULeb128Ext::write_uleb128
+//! [`ReadULeb128Buf`]: crate::ReadULeb128Buf
+//! [`WriteULeb128Buf`]: crate::WriteULeb128Buf
+//! [`Buf`]: https://docs.rs/bytes/latest/bytes/trait.Buf.html
+//! [`BufMut`]: https://docs.rs/bytes/latest/bytes/trait.BufMut.html
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
-#[macro_use]
-extern crate quick_error;
-
+#[cfg(feature = "bytes")]
+mod buf;
 mod error;
+mod int;
+#[cfg(feature = "std")]
 mod io;
+mod slice;
 
+#[cfg(feature = "bytes")]
+pub use buf::{ReadULeb128Buf, WriteULeb128Buf};
 pub use error::{Error, Result};
-pub use io::{ReadULeb128Ext, WriteULeb128Ext};
-
-pub(crate) const ULEB128_U32_MAX_LENGTH: usize = 5;
-pub(crate) const ULEB128_U64_MAX_LENGTH: usize = 10;
+pub use int::Uleb128Int;
+#[cfg(feature = "std")]
+pub use io::{ReadSLeb128Ext, ReadULeb128Ext, WriteSLeb128Ext, WriteULeb128Ext};
+pub use slice::{
+    uleb128_u32_decode, uleb128_u32_encode, uleb128_u64_decode, uleb128_u64_encode,
+};
 
-const fn max_value(len: usize) -> usize {
-    128usize.pow(len as u32) - 1
+// The number of bytes needed to encode a value of a given bit width as
+// unsigned LEB128.
+const fn max_length(bits: usize) -> usize {
+    bits.div_ceil(7)
 }
 
+pub(crate) const ULEB128_U8_MAX_LENGTH: usize = max_length(u8::BITS as usize);
+pub(crate) const ULEB128_U16_MAX_LENGTH: usize = max_length(u16::BITS as usize);
+pub(crate) const ULEB128_U32_MAX_LENGTH: usize = max_length(u32::BITS as usize);
+pub(crate) const ULEB128_U64_MAX_LENGTH: usize = max_length(u64::BITS as usize);
+pub(crate) const ULEB128_U128_MAX_LENGTH: usize = max_length(u128::BITS as usize);
+pub(crate) const ULEB128_USIZE_MAX_LENGTH: usize = max_length(usize::BITS as usize);
+
+pub(crate) const VALUE_MASK: u8 = 0b0111_1111;
+pub(crate) const VALUE_LENGTH: usize = 7;
+
 macro_rules! len_body {
     ($n:ident, $ty:ty, $len:expr) => {{
         for len in 1..$len {
-            if $n <= max_value(len) as $ty {
+            let max = (128 as $ty).pow(len as u32) - 1;
+            if $n <= max {
                 return len;
             }
         }
@@ -66,6 +168,40 @@ macro_rules! len_body {
     }};
 }
 
+/// Get the length of the unsigned 8-bit integer's [unsigned LEB128]
+/// representation in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u8_len;
+///
+/// assert_eq!(1, uleb128_u8_len(127));
+/// assert_eq!(2, uleb128_u8_len(128));
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+pub fn uleb128_u8_len(n: u8) -> usize {
+    len_body!(n, u8, ULEB128_U8_MAX_LENGTH)
+}
+
+/// Get the length of the unsigned 16-bit integer's [unsigned LEB128]
+/// representation in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u16_len;
+///
+/// assert_eq!(1, uleb128_u16_len(127));
+/// assert_eq!(2, uleb128_u16_len(128));
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+pub fn uleb128_u16_len(n: u16) -> usize {
+    len_body!(n, u16, ULEB128_U16_MAX_LENGTH)
+}
+
 /// Get the length of the unsigned 32-bit integer's [unsigned LEB128]
 /// representation in bytes.
 ///
@@ -99,3 +235,37 @@ pub fn uleb128_u32_len(n: u32) -> usize {
 pub fn uleb128_u64_len(n: u64) -> usize {
     len_body!(n, u64, ULEB128_U64_MAX_LENGTH)
 }
+
+/// Get the length of the unsigned 128-bit integer's [unsigned LEB128]
+/// representation in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u128_len;
+///
+/// assert_eq!(5, uleb128_u128_len(34_359_738_367));
+/// assert_eq!(6, uleb128_u128_len(34_359_738_368));
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+pub fn uleb128_u128_len(n: u128) -> usize {
+    len_body!(n, u128, ULEB128_U128_MAX_LENGTH)
+}
+
+/// Get the length of the [`usize`] integer's [unsigned LEB128]
+/// representation in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_usize_len;
+///
+/// assert_eq!(1, uleb128_usize_len(127));
+/// assert_eq!(2, uleb128_usize_len(128));
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+pub fn uleb128_usize_len(n: usize) -> usize {
+    len_body!(n, usize, ULEB128_USIZE_MAX_LENGTH)
+}
@@ -0,0 +1,160 @@
+use crate::error::{Error, Result};
+use crate::{VALUE_LENGTH, VALUE_MASK};
+
+macro_rules! encode_body {
+    ($value:ident, $buf:expr, $ty:ty) => {{
+        let mut value = $value;
+        let mut written = 0;
+
+        loop {
+            let mut byte = (value & VALUE_MASK as $ty) as u8;
+            value >>= VALUE_LENGTH;
+
+            if value != 0 {
+                byte |= !VALUE_MASK;
+            }
+
+            let slot = $buf.get_mut(written).ok_or(Error::BufferTooSmall)?;
+            *slot = byte;
+            written += 1;
+
+            if value == 0 {
+                return Ok(written);
+            }
+        }
+    }};
+}
+
+/// Encode an unsigned 32-bit integer as [unsigned LEB128] into `buf`,
+/// returning the number of bytes written.
+///
+/// Unlike [`WriteULeb128Ext::write_uleb128_u32`], this does not require
+/// [`std::io::Write`], making it usable in `no_std` contexts.
+///
+/// # Errors
+///
+/// If `buf` is not large enough to hold the encoded value, an
+/// [`Error::BufferTooSmall`] is immediately returned.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u32_encode;
+///
+/// let mut buf = [0; 2];
+/// assert_eq!(2, uleb128_u32_encode(128, &mut buf).unwrap());
+/// assert_eq!(buf, [0b1000_0000, 0b0000_0001]);
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`WriteULeb128Ext::write_uleb128_u32`]: crate::WriteULeb128Ext::write_uleb128_u32
+pub fn uleb128_u32_encode(value: u32, buf: &mut [u8]) -> Result<usize> {
+    encode_body!(value, buf, u32)
+}
+
+/// Encode an unsigned 64-bit integer as [unsigned LEB128] into `buf`,
+/// returning the number of bytes written.
+///
+/// Unlike [`WriteULeb128Ext::write_uleb128_u64`], this does not require
+/// [`std::io::Write`], making it usable in `no_std` contexts.
+///
+/// # Errors
+///
+/// If `buf` is not large enough to hold the encoded value, an
+/// [`Error::BufferTooSmall`] is immediately returned.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u64_encode;
+///
+/// let mut buf = [0; 2];
+/// assert_eq!(2, uleb128_u64_encode(128, &mut buf).unwrap());
+/// assert_eq!(buf, [0b1000_0000, 0b0000_0001]);
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`WriteULeb128Ext::write_uleb128_u64`]: crate::WriteULeb128Ext::write_uleb128_u64
+pub fn uleb128_u64_encode(value: u64, buf: &mut [u8]) -> Result<usize> {
+    encode_body!(value, buf, u64)
+}
+
+/// Decode an unsigned 32-bit integer encoded in [unsigned LEB128] from
+/// `buf`, returning the value and the number of bytes read.
+///
+/// Unlike [`ReadULeb128Ext::read_uleb128_u32`], this does not require
+/// [`std::io::Read`], making it usable in `no_std` contexts.
+///
+/// # Errors
+///
+/// If `buf` ends before a complete value is decoded, an
+/// [`Error::UnexpectedEof`] is immediately returned.
+///
+/// If this function encounters an encoded number with a length in bytes
+/// greater than what is permitted, an [`Error::LengthOverflow`] is
+/// immediately returned.
+///
+/// If this function encounters a final byte whose significant bits
+/// exceed the range of a 32-bit integer, an [`Error::ValueOverflow`] is
+/// immediately returned.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u32_decode;
+///
+/// let (value, read) = uleb128_u32_decode(&[0b1000_0000, 0b0000_0001]).unwrap();
+/// assert_eq!(128, value);
+/// assert_eq!(2, read);
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`ReadULeb128Ext::read_uleb128_u32`]: crate::ReadULeb128Ext::read_uleb128_u32
+pub fn uleb128_u32_decode(buf: &[u8]) -> Result<(u32, usize)> {
+    let mut pos = 0;
+    crate::int::decode(|| {
+        let byte = *buf.get(pos).ok_or(Error::UnexpectedEof)?;
+        pos += 1;
+        Ok(byte)
+    })
+}
+
+/// Decode an unsigned 64-bit integer encoded in [unsigned LEB128] from
+/// `buf`, returning the value and the number of bytes read.
+///
+/// Unlike [`ReadULeb128Ext::read_uleb128_u64`], this does not require
+/// [`std::io::Read`], making it usable in `no_std` contexts.
+///
+/// # Errors
+///
+/// If `buf` ends before a complete value is decoded, an
+/// [`Error::UnexpectedEof`] is immediately returned.
+///
+/// If this function encounters an encoded number with a length in bytes
+/// greater than what is permitted, an [`Error::LengthOverflow`] is
+/// immediately returned.
+///
+/// If this function encounters a final byte whose significant bits
+/// exceed the range of a 64-bit integer, an [`Error::ValueOverflow`] is
+/// immediately returned.
+///
+/// # Examples
+///
+/// ```
+/// use uleb128::uleb128_u64_decode;
+///
+/// let (value, read) = uleb128_u64_decode(&[0b1000_0000, 0b0000_0001]).unwrap();
+/// assert_eq!(128, value);
+/// assert_eq!(2, read);
+/// ```
+///
+/// [unsigned LEB128]: https://en.wikipedia.org/wiki/LEB128#Unsigned_LEB128
+/// [`ReadULeb128Ext::read_uleb128_u64`]: crate::ReadULeb128Ext::read_uleb128_u64
+pub fn uleb128_u64_decode(buf: &[u8]) -> Result<(u64, usize)> {
+    let mut pos = 0;
+    crate::int::decode(|| {
+        let byte = *buf.get(pos).ok_or(Error::UnexpectedEof)?;
+        pos += 1;
+        Ok(byte)
+    })
+}